@@ -1,4 +1,6 @@
 use crate::models::*;
+use crate::offshore::{size_monopile, MonopileSizing};
+use crate::structures::structural_loads;
 use crate::types::*;
 use serde::Serialize;
 use pyo3::prelude::*;
@@ -16,6 +18,11 @@ impl Solver {
         Self { cfg }
     }
 
+    /// The turbine configuration this solver was built from.
+    pub fn config(&self) -> &TurbineConfig {
+        &self.cfg
+    }
+
     /// Swept area A = π r²
     pub fn rotor_area(&self) -> f64 {
         PI * self.cfg.constraints.blade_radius.powi(2)
@@ -26,16 +33,35 @@ impl Solver {
         self.cfg.constraints.blade_radius
     }
 
+    /// Mean of `U(z)^3` across the swept disc, integrating the power-law
+    /// shear profile from `hub_height - radius` to `hub_height + radius` so
+    /// that rotor area and shear interact correctly.
+    pub fn mean_cubed_wind_speed(&self) -> f64 {
+        let env = &self.cfg.env;
+        let radius = self.cfg.constraints.blade_radius;
+        let z_lo = (env.hub_height - radius).max(0.0);
+        let z_hi = env.hub_height + radius;
+        if z_hi <= z_lo {
+            return env.wind_speed_at(env.hub_height).powi(3);
+        }
+        const STEPS: usize = 50;
+        let dz = (z_hi - z_lo) / STEPS as f64;
+        let sum: f64 = (0..STEPS)
+            .map(|i| env.wind_speed_at(z_lo + (i as f64 + 0.5) * dz).powi(3))
+            .sum();
+        sum / STEPS as f64
+    }
+
     /// Determine required TSR for target wattage
     pub fn required_tsr(&self) -> f64 {
         let area = self.rotor_area();
         let _betz = betz_limit(&self.cfg.env);
+        let mean_u3 = self.mean_cubed_wind_speed();
         let mut tsr = optimal_tsr(self.cfg.constraints.num_blades);
         // Iterate until power matches target
         for _ in 0..20 {
-            let cp = cp_at_tsr(tsr);
-            let power = cp * 0.5 * self.cfg.env.air_density * area
-                * self.cfg.env.wind_speed.powi(3);
+            let cp = cp_at_tsr(tsr, self.cfg.constraints.num_blades, self.cfg.constraints.blade_radius);
+            let power = cp * 0.5 * self.cfg.env.air_density * area * mean_u3;
             let err = (power - self.cfg.target_wattage) / self.cfg.target_wattage;
             if err.abs() < 0.01 { break; }
             tsr += -err * tsr * 0.1;
@@ -43,12 +69,46 @@ impl Solver {
         tsr
     }
 
-    /// Generator RPM = (TSR * wind_speed) / radius * 60/(2π)
+    /// Generator RPM = (TSR * hub-height wind speed) / radius * 60/(2π)
     pub fn generator_rpm(&self, tsr: f64) -> f64 {
-        let omega = tsr * self.cfg.env.wind_speed / self.cfg.constraints.blade_radius; // rad/s
+        let hub_wind = self.cfg.env.wind_speed_at(self.cfg.env.hub_height);
+        let omega = tsr * hub_wind / self.cfg.constraints.blade_radius; // rad/s
         omega * 60.0 / (2.0 * PI)
     }
 
+    /// Blade tip speed `V_tip = Ω·R = tsr · U_hub` implied by a tip-speed ratio.
+    pub fn tip_speed(&self, tsr: f64) -> f64 {
+        tsr * self.cfg.env.wind_speed_at(self.cfg.env.hub_height)
+    }
+
+    /// Aeroacoustic noise estimate for the design operating point.
+    ///
+    /// If `max_tip_speed` is set and the TSR that meets `target_wattage`
+    /// would exceed it, the operating TSR is derated to the limit so the
+    /// design stays within the acoustic envelope, and `derated` is flagged.
+    pub fn noise_summary(&self) -> NoiseSummary {
+        let tsr = self.required_tsr();
+        let tip_speed = self.tip_speed(tsr);
+        let max_tip_speed = self.cfg.constraints.max_tip_speed;
+
+        let (operating_tsr, derated) = match max_tip_speed {
+            Some(limit) if limit > 0.0 && tip_speed > limit => {
+                let hub_wind = self.cfg.env.wind_speed_at(self.cfg.env.hub_height);
+                (limit / hub_wind.max(1e-6), true)
+            }
+            _ => (tsr, false),
+        };
+        let operating_tip_speed = self.tip_speed(operating_tsr);
+
+        NoiseSummary {
+            operating_tsr,
+            tip_speed: operating_tip_speed,
+            sound_power_level_dba: sound_power_level(operating_tip_speed, self.rotor_area()),
+            max_tip_speed,
+            derated,
+        }
+    }
+
     /// Estimate gearbox ratio if generator is low‑speed
     pub fn gearbox_ratio(&self, rpm: f64, desired_gen_rpm: f64) -> f64 {
         if rpm <= desired_gen_rpm { 1.0 } else { rpm / desired_gen_rpm }
@@ -58,11 +118,63 @@ impl Solver {
     pub fn cut_in(&self) -> f64 { 2.5 } // m/s
     pub fn cut_out(&self) -> f64 { 25.0 } // m/s
 
+    /// Mechanical power at wind speed `v` for a rotor holding power
+    /// coefficient `cp`, capped at `target_wattage` above rated speed and
+    /// zero outside the cut-in/cut-out band.
+    pub fn power_at_wind_speed(&self, v: f64, cp: f64) -> f64 {
+        if v < self.cut_in() || v > self.cut_out() {
+            return 0.0;
+        }
+        let power = cp * 0.5 * self.cfg.env.air_density * self.rotor_area() * v.powi(3);
+        power.min(self.cfg.target_wattage)
+    }
+
+    /// Power coefficient at the solved design operating point (`required_tsr`).
+    pub fn design_cp(&self) -> f64 {
+        cp_at_tsr(self.required_tsr(), self.cfg.constraints.num_blades, self.cfg.constraints.blade_radius)
+    }
+
+    /// Annual Energy Production (kWh/year) and capacity factor, integrating
+    /// the power curve against a Weibull wind-speed distribution with shape
+    /// `k` and scale `c`: `f(v) = (k/c)(v/c)^(k-1) * exp(-(v/c)^k)`.
+    pub fn annual_energy_production(&self, k: f64, c: f64) -> AnnualEnergyProduction {
+        let cp = self.design_cp();
+        const STEPS: usize = 200;
+        let dv = (self.cut_out() - self.cut_in()) / STEPS as f64;
+        let mut aep_wh = 0.0;
+        for i in 0..STEPS {
+            let v = self.cut_in() + (i as f64 + 0.5) * dv;
+            let density = (k / c) * (v / c).powf(k - 1.0) * (-(v / c).powf(k)).exp();
+            aep_wh += density * self.power_at_wind_speed(v, cp) * dv;
+        }
+        aep_wh *= 8760.0; // h/year
+        AnnualEnergyProduction {
+            aep_kwh: aep_wh / 1000.0,
+            capacity_factor: aep_wh / (self.cfg.target_wattage * 8760.0),
+        }
+    }
+
     /// Full design summary
     pub fn design_summary(&self) -> DesignSummary {
-        let tsr = self.required_tsr();
+        let noise = self.noise_summary();
+        let tsr = noise.operating_tsr;
         let rpm = self.generator_rpm(tsr);
-        let gear = self.gearbox_ratio(rpm, 1500.0); // 1.5 kRPM typical
+        let sizing = size_generator(self.cfg.constraints.generator_type, rpm, self.cfg.target_wattage);
+        // A turbine gearbox steps *up* from the slow rotor to the faster
+        // generator shaft, so the ratio is target/rotor rpm, not
+        // `gearbox_ratio` (which assumes a step-down).
+        let gear = if sizing.gearbox_required && rpm > 0.0 { sizing.target_rpm / rpm } else { 1.0 };
+        // Rayleigh distribution (k = 2) scaled to the hub-height wind speed,
+        // a reasonable default absent a measured Weibull fit.
+        let hub_wind = self.cfg.env.wind_speed_at(self.cfg.env.hub_height);
+        let aep = self.annual_energy_production(2.0, hub_wind);
+        let loads = structural_loads(
+            self.cfg.env.air_density,
+            hub_wind,
+            self.cfg.constraints.num_blades,
+            self.cfg.constraints.blade_radius,
+        );
+        let monopile = self.cfg.offshore.as_ref().map(size_monopile);
         DesignSummary {
             rotor_area: self.rotor_area(),
             blade_length: self.blade_length(),
@@ -72,10 +184,41 @@ impl Solver {
             generator_type: self.cfg.constraints.generator_type,
             cut_in: self.cut_in(),
             cut_out: self.cut_out(),
+            aep_kwh: aep.aep_kwh,
+            capacity_factor: aep.capacity_factor,
+            gearbox_required: sizing.gearbox_required,
+            generator_pole_count: sizing.pole_count,
+            generator_rated_torque: sizing.rated_torque,
+            generator_mass: sizing.active_mass,
+            blade_mass: loads.blade_mass,
+            hub_mass: loads.hub_mass,
+            rotor_bending_moment: loads.rotor_bending_moment,
+            monopile,
+            tip_speed: noise.tip_speed,
+            sound_power_level_dba: noise.sound_power_level_dba,
+            noise_derated: noise.derated,
         }
     }
 }
 
+/// Annual Energy Production result
+#[derive(Debug, Serialize)]
+pub struct AnnualEnergyProduction {
+    pub aep_kwh: f64,
+    pub capacity_factor: f64,
+}
+
+/// Noise/tip-speed result for the design's operating point, after any
+/// derating needed to respect `Constraints::max_tip_speed`.
+#[derive(Debug, Serialize)]
+pub struct NoiseSummary {
+    pub operating_tsr: f64,
+    pub tip_speed: f64,
+    pub sound_power_level_dba: f64,
+    pub max_tip_speed: Option<f64>,
+    pub derated: bool,
+}
+
 /// Result struct – serialisable to JSON/CSV
 #[derive(Debug, Serialize)]
 pub struct DesignSummary {
@@ -87,6 +230,19 @@ pub struct DesignSummary {
     pub generator_type: GeneratorType,
     pub cut_in: f64,
     pub cut_out: f64,
+    pub aep_kwh: f64,
+    pub capacity_factor: f64,
+    pub gearbox_required: bool,
+    pub generator_pole_count: u32,
+    pub generator_rated_torque: f64,
+    pub generator_mass: f64,
+    pub blade_mass: f64,
+    pub hub_mass: f64,
+    pub rotor_bending_moment: f64,
+    pub monopile: Option<MonopileSizing>,
+    pub tip_speed: f64,
+    pub sound_power_level_dba: f64,
+    pub noise_derated: bool,
 }
 
 impl IntoPy<PyObject> for DesignSummary {
@@ -100,6 +256,37 @@ impl IntoPy<PyObject> for DesignSummary {
         dict.set_item("generator_type", format!("{:?}", self.generator_type)).unwrap();
         dict.set_item("cut_in", self.cut_in).unwrap();
         dict.set_item("cut_out", self.cut_out).unwrap();
+        dict.set_item("aep_kwh", self.aep_kwh).unwrap();
+        dict.set_item("capacity_factor", self.capacity_factor).unwrap();
+        dict.set_item("gearbox_required", self.gearbox_required).unwrap();
+        dict.set_item("generator_pole_count", self.generator_pole_count).unwrap();
+        dict.set_item("generator_rated_torque", self.generator_rated_torque).unwrap();
+        dict.set_item("generator_mass", self.generator_mass).unwrap();
+        dict.set_item("blade_mass", self.blade_mass).unwrap();
+        dict.set_item("hub_mass", self.hub_mass).unwrap();
+        dict.set_item("rotor_bending_moment", self.rotor_bending_moment).unwrap();
+        if let Some(monopile) = self.monopile {
+            let monopile_dict = PyDict::new(py);
+            monopile_dict.set_item("diameter", monopile.diameter).unwrap();
+            monopile_dict.set_item("wall_thickness", monopile.wall_thickness).unwrap();
+            monopile_dict.set_item("base_shear", monopile.base_shear).unwrap();
+            monopile_dict.set_item("base_moment", monopile.base_moment).unwrap();
+            dict.set_item("monopile", monopile_dict).unwrap();
+        } else {
+            dict.set_item("monopile", py.None()).unwrap();
+        }
+        dict.set_item("tip_speed", self.tip_speed).unwrap();
+        dict.set_item("sound_power_level_dba", self.sound_power_level_dba).unwrap();
+        dict.set_item("noise_derated", self.noise_derated).unwrap();
+        dict.into()
+    }
+}
+
+impl IntoPy<PyObject> for AnnualEnergyProduction {
+    fn into_py(self, py: Python) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("aep_kwh", self.aep_kwh).unwrap();
+        dict.set_item("capacity_factor", self.capacity_factor).unwrap();
         dict.into()
     }
 }
\ No newline at end of file
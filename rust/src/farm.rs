@@ -0,0 +1,157 @@
+use crate::core::Solver;
+use crate::models::thrust_coefficient;
+use crate::types::*;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde::{Deserialize, Serialize};
+use std::f64::consts::PI;
+
+/// Position of a turbine within a farm layout (metres, wind assumed along +x).
+#[pyclass]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct TurbinePosition {
+    #[pyo3(get, set)]
+    pub x: f64,
+    #[pyo3(get, set)]
+    pub y: f64,
+}
+
+#[pymethods]
+impl TurbinePosition {
+    #[new]
+    pub fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+/// A wind farm: one shared turbine configuration deployed at several
+/// positions, used to estimate array wake losses with the Jensen/Park model.
+#[derive(Clone, Debug)]
+pub struct Farm {
+    pub positions: Vec<TurbinePosition>,
+    pub config: TurbineConfig,
+    pub wake_decay: f64, // k, ~0.075 onshore, ~0.04 offshore
+}
+
+impl Farm {
+    pub fn new(positions: Vec<TurbinePosition>, config: TurbineConfig, wake_decay: f64) -> Self {
+        Self { positions, config, wake_decay }
+    }
+
+    /// Jensen/Park centerline velocity deficit fraction `ΔU/U` at distance
+    /// `x` downstream of an upstream rotor of radius `r0`:
+    /// `ΔU/U = (1 - sqrt(1 - Ct)) · (r0 / (r0 + k·x))²`.
+    fn single_wake_deficit(&self, x: f64, r0: f64, ct: f64) -> f64 {
+        if x <= 0.0 || ct <= 0.0 {
+            return 0.0;
+        }
+        (1.0 - (1.0 - ct).max(0.0).sqrt()) * (r0 / (r0 + self.wake_decay * x)).powi(2)
+    }
+
+    /// Wake radius at distance `x` downstream of a rotor of radius `r0`.
+    fn wake_radius(&self, x: f64, r0: f64) -> f64 {
+        r0 + self.wake_decay * x
+    }
+
+    /// Fraction of a downstream rotor's swept area that overlaps an upstream
+    /// wake of radius `r_wake`, whose centerline is a lateral distance `d`
+    /// away from the rotor centre.
+    fn overlap_fraction(&self, rotor_radius: f64, r_wake: f64, d: f64) -> f64 {
+        if d >= rotor_radius + r_wake {
+            return 0.0;
+        }
+        let rotor_area = PI * rotor_radius.powi(2);
+        if d <= (r_wake - rotor_radius).abs() {
+            // One circle is fully contained in the other.
+            return (PI * rotor_radius.min(r_wake).powi(2) / rotor_area).min(1.0);
+        }
+        // Two-circle lens (circular segment) intersection area.
+        let d = d.max(1e-9);
+        let alpha = ((d.powi(2) + rotor_radius.powi(2) - r_wake.powi(2)) / (2.0 * d * rotor_radius))
+            .clamp(-1.0, 1.0)
+            .acos();
+        let beta = ((d.powi(2) + r_wake.powi(2) - rotor_radius.powi(2)) / (2.0 * d * r_wake))
+            .clamp(-1.0, 1.0)
+            .acos();
+        let area = rotor_radius.powi(2) * (alpha - alpha.sin() * alpha.cos())
+            + r_wake.powi(2) * (beta - beta.sin() * beta.cos());
+        (area / rotor_area).clamp(0.0, 1.0)
+    }
+
+    /// Effective hub-height wind speed at each turbine, combining every
+    /// overlapping upstream wake by sum-of-squares:
+    /// `(ΔU_total/U)² = Σ (ΔU_i/U)²`.
+    pub fn effective_wind_speeds(&self) -> Vec<f64> {
+        let radius = self.config.constraints.blade_radius;
+        let ct = thrust_coefficient(Solver::new(self.config.clone()).design_cp());
+        let u_free = self.config.env.wind_speed_at(self.config.env.hub_height);
+
+        self.positions
+            .iter()
+            .map(|turbine| {
+                let deficit_sq_sum: f64 = self
+                    .positions
+                    .iter()
+                    .filter_map(|upstream| {
+                        let dx = turbine.x - upstream.x;
+                        if dx <= 0.0 {
+                            return None; // not upstream of this turbine
+                        }
+                        let dy = (turbine.y - upstream.y).abs();
+                        let r_wake = self.wake_radius(dx, radius);
+                        let overlap = self.overlap_fraction(radius, r_wake, dy);
+                        if overlap <= 0.0 {
+                            return None;
+                        }
+                        let deficit = self.single_wake_deficit(dx, radius, ct) * overlap;
+                        Some(deficit * deficit)
+                    })
+                    .sum();
+                // Dense arrays with several directly-aligned upstream rotors
+                // can drive the combined deficit past 1; floor it so the
+                // effective speed never goes negative.
+                u_free * (1.0 - deficit_sq_sum.sqrt()).max(0.0)
+            })
+            .collect()
+    }
+
+    /// Per-turbine power, total farm power, and array efficiency (farm power
+    /// relative to every turbine running undisturbed at the free-stream speed).
+    pub fn summary(&self) -> FarmSummary {
+        let solver = Solver::new(self.config.clone());
+        let cp = solver.design_cp();
+        let u_free = self.config.env.wind_speed_at(self.config.env.hub_height);
+
+        let effective_wind_speeds = self.effective_wind_speeds();
+        let turbine_powers: Vec<f64> = effective_wind_speeds
+            .iter()
+            .map(|&u| solver.power_at_wind_speed(u, cp))
+            .collect();
+        let total_power: f64 = turbine_powers.iter().sum();
+
+        let ideal_total = solver.power_at_wind_speed(u_free, cp) * self.positions.len() as f64;
+        let array_efficiency = if ideal_total > 0.0 { total_power / ideal_total } else { 0.0 };
+
+        FarmSummary { effective_wind_speeds, turbine_powers, total_power, array_efficiency }
+    }
+}
+
+/// Farm-wide wake-loss summary
+#[derive(Debug, Serialize)]
+pub struct FarmSummary {
+    pub effective_wind_speeds: Vec<f64>,
+    pub turbine_powers: Vec<f64>,
+    pub total_power: f64,
+    pub array_efficiency: f64,
+}
+
+impl IntoPy<PyObject> for FarmSummary {
+    fn into_py(self, py: Python) -> PyObject {
+        let dict = PyDict::new(py);
+        dict.set_item("effective_wind_speeds", self.effective_wind_speeds).unwrap();
+        dict.set_item("turbine_powers", self.turbine_powers).unwrap();
+        dict.set_item("total_power", self.total_power).unwrap();
+        dict.set_item("array_efficiency", self.array_efficiency).unwrap();
+        dict.into()
+    }
+}
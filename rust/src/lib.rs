@@ -2,12 +2,19 @@ use pyo3::prelude::*;
 mod types;
 mod models;
 mod core;
+mod farm;
+mod offshore;
+mod optimize;
+mod structures;
 
 #[cfg(test)]
 mod tests;
 
 use crate::core::Solver;
+use crate::farm::{Farm, TurbinePosition};
+use crate::optimize::{self, Bounds, Objective};
 use crate::types::*;
+use pyo3::exceptions::PyValueError;
 
 /// Expose Rust structs to Python
 #[pyclass]
@@ -19,13 +26,16 @@ pub struct PyTurbineConfig {
     env: Env,
     #[pyo3(get, set)]
     constraints: Constraints,
+    #[pyo3(get, set)]
+    offshore: Option<OffshoreConditions>,
 }
 
 #[pymethods]
 impl PyTurbineConfig {
     #[new]
-    pub fn new(target_wattage: f64, env: Env, constraints: Constraints) -> Self {
-        Self { target_wattage, env, constraints }
+    #[pyo3(signature = (target_wattage, env, constraints, offshore=None))]
+    pub fn new(target_wattage: f64, env: Env, constraints: Constraints, offshore: Option<OffshoreConditions>) -> Self {
+        Self { target_wattage, env, constraints, offshore }
     }
 }
 
@@ -48,6 +58,71 @@ impl PySolver {
         let summary = self.solver.design_summary();
         Python::with_gil(|py| Ok(summary.into_py(py)))
     }
+
+    /// Annual Energy Production (kWh/year) and capacity factor for a
+    /// Weibull wind-speed distribution with shape `k` and scale `c`.
+    pub fn annual_energy_production(&self, k: f64, c: f64) -> PyResult<PyObject> {
+        let aep = self.solver.annual_energy_production(k, c);
+        Python::with_gil(|py| Ok(aep.into_py(py)))
+    }
+
+    /// Search `blade_radius_bounds`/`num_blades_bounds` for the design that
+    /// maximizes `objective` ("aep", "capacity_factor", or "blade_mass" to
+    /// minimize), holding everything else fixed. Returns a dict with the
+    /// best `config`, its `summary`, and the achieved `objective_value`.
+    pub fn optimize(
+        &self,
+        blade_radius_bounds: (f64, f64),
+        num_blades_bounds: (u8, u8),
+        objective: &str,
+    ) -> PyResult<PyObject> {
+        let objective = match objective {
+            "aep" => Objective::MaximizeAep,
+            "capacity_factor" => Objective::MaximizeCapacityFactor,
+            "blade_mass" => Objective::MinimizeBladeMass,
+            other => {
+                return Err(PyValueError::new_err(format!(
+                    "unknown objective '{other}', expected one of: aep, capacity_factor, blade_mass"
+                )))
+            }
+        };
+        let bounds = Bounds { blade_radius: blade_radius_bounds, num_blades: num_blades_bounds };
+        let result = optimize::optimize(self.solver.config(), bounds, objective);
+
+        Python::with_gil(|py| {
+            let dict = pyo3::types::PyDict::new(py);
+            let config: PyTurbineConfig = result.config.into();
+            dict.set_item("config", config.into_py(py))?;
+            dict.set_item("summary", result.summary.into_py(py))?;
+            dict.set_item("objective_value", result.objective_value)?;
+            Ok(dict.into())
+        })
+    }
+}
+
+/// Wrapper around Farm
+#[pyclass]
+pub struct PyFarm {
+    farm: Farm,
+}
+
+#[pymethods]
+impl PyFarm {
+    #[new]
+    pub fn new(positions: Vec<TurbinePosition>, cfg: PyTurbineConfig, wake_decay: f64) -> Self {
+        Self { farm: Farm::new(positions, cfg.into(), wake_decay) }
+    }
+
+    /// Effective hub-height wind speed at each turbine after array wake losses.
+    pub fn effective_wind_speeds(&self) -> Vec<f64> {
+        self.farm.effective_wind_speeds()
+    }
+
+    /// Per-turbine power, total farm power, and array efficiency.
+    pub fn farm_summary(&self) -> PyResult<PyObject> {
+        let summary = self.farm.summary();
+        Python::with_gil(|py| Ok(summary.into_py(py)))
+    }
 }
 
 /// Implement conversion
@@ -57,6 +132,18 @@ impl From<PyTurbineConfig> for TurbineConfig {
             target_wattage: p.target_wattage,
             env: p.env,
             constraints: p.constraints,
+            offshore: p.offshore,
+        }
+    }
+}
+
+impl From<TurbineConfig> for PyTurbineConfig {
+    fn from(cfg: TurbineConfig) -> Self {
+        PyTurbineConfig {
+            target_wattage: cfg.target_wattage,
+            env: cfg.env,
+            constraints: cfg.constraints,
+            offshore: cfg.offshore,
         }
     }
 }
@@ -67,7 +154,10 @@ fn wind_calc(_py: Python, m: &PyModule) -> PyResult<()> {
     m.add_class::<PyEnv>()?;
     m.add_class::<PyConstraints>()?;
     m.add_class::<PyGeneratorType>()?;
+    m.add_class::<PyOffshoreConditions>()?;
     m.add_class::<PyTurbineConfig>()?;
     m.add_class::<PySolver>()?;
+    m.add_class::<TurbinePosition>()?;
+    m.add_class::<PyFarm>()?;
     Ok(())
 }
\ No newline at end of file
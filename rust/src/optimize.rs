@@ -0,0 +1,122 @@
+use crate::core::{DesignSummary, Solver};
+use crate::structures::structural_loads;
+use crate::types::*;
+
+/// Lower/upper bounds for the design variables the optimizer is allowed to vary.
+#[derive(Clone, Copy, Debug)]
+pub struct Bounds {
+    pub blade_radius: (f64, f64), // m
+    pub num_blades: (u8, u8),
+}
+
+/// Objective the optimizer searches for (always maximized internally).
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Objective {
+    MaximizeAep,
+    MaximizeCapacityFactor,
+    MinimizeBladeMass,
+}
+
+/// Best design found, and the summary/objective value it scored.
+#[derive(Debug)]
+pub struct OptimizationResult {
+    pub config: TurbineConfig,
+    pub summary: DesignSummary,
+    pub objective_value: f64,
+}
+
+/// Objective value for a candidate config, always oriented so that "higher is
+/// better" regardless of whether the underlying quantity is being maximized
+/// or minimized.
+///
+/// Candidates that violate `Constraints::max_tip_speed` are penalized far
+/// below any feasible design's score (scaled by the violation) so the search
+/// is steered back towards the acoustic envelope rather than simply ignoring
+/// it.
+fn evaluate(cfg: &TurbineConfig, objective: Objective) -> f64 {
+    let solver = Solver::new(cfg.clone());
+    let hub_wind = cfg.env.wind_speed_at(cfg.env.hub_height);
+
+    if let Some(limit) = cfg.constraints.max_tip_speed {
+        let tip_speed = solver.tip_speed(solver.required_tsr());
+        if limit > 0.0 && tip_speed > limit {
+            return -1e9 * (1.0 + (tip_speed - limit));
+        }
+    }
+
+    match objective {
+        Objective::MaximizeAep => solver.annual_energy_production(2.0, hub_wind).aep_kwh,
+        Objective::MaximizeCapacityFactor => solver.annual_energy_production(2.0, hub_wind).capacity_factor,
+        Objective::MinimizeBladeMass => {
+            let loads = structural_loads(
+                cfg.env.air_density,
+                hub_wind,
+                cfg.constraints.num_blades,
+                cfg.constraints.blade_radius,
+            );
+            -loads.blade_mass
+        }
+    }
+}
+
+/// Gradient-free pattern search for the `x` in `[lo, hi]` that maximizes `f`,
+/// starting from the bounds midpoint and repeatedly probing +/- a step that
+/// halves whenever neither probe improves on the current best.
+fn pattern_search_1d<F: FnMut(f64) -> f64>(lo: f64, hi: f64, mut f: F) -> f64 {
+    let mut x = 0.5 * (lo + hi);
+    let mut step = 0.25 * (hi - lo).max(1e-6);
+    let mut best = f(x);
+    for _ in 0..40 {
+        let mut improved = false;
+        for candidate in [x + step, x - step] {
+            let candidate = candidate.clamp(lo, hi);
+            let value = f(candidate);
+            if value > best {
+                best = value;
+                x = candidate;
+                improved = true;
+            }
+        }
+        if !improved {
+            step *= 0.5;
+        }
+        if step < 1e-4 {
+            break;
+        }
+    }
+    x
+}
+
+/// Search `bounds` for the `Constraints` that maximize `objective`, holding
+/// everything else in `base` fixed.
+///
+/// `num_blades` only spans a handful of integers, so it's scanned
+/// exhaustively; for each candidate blade count, `blade_radius` is tuned with
+/// a 1-D pattern search. No analytic derivatives are required, so this works
+/// for any objective built from the solver's other models.
+pub fn optimize(base: &TurbineConfig, bounds: Bounds, objective: Objective) -> OptimizationResult {
+    let (blade_lo, blade_hi) = bounds.blade_radius;
+    let (blades_lo, blades_hi) = bounds.num_blades;
+
+    let mut best_cfg = base.clone();
+    let mut best_value = f64::NEG_INFINITY;
+
+    for num_blades in blades_lo..=blades_hi {
+        let mut cfg = base.clone();
+        cfg.constraints.num_blades = num_blades;
+        let radius = pattern_search_1d(blade_lo, blade_hi, |r| {
+            cfg.constraints.blade_radius = r;
+            evaluate(&cfg, objective)
+        });
+        cfg.constraints.blade_radius = radius;
+
+        let value = evaluate(&cfg, objective);
+        if value > best_value {
+            best_value = value;
+            best_cfg = cfg;
+        }
+    }
+
+    let summary = Solver::new(best_cfg.clone()).design_summary();
+    OptimizationResult { config: best_cfg, summary, objective_value: best_value }
+}
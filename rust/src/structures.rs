@@ -0,0 +1,47 @@
+use crate::models::rotor_solidity;
+use serde::Serialize;
+use std::f64::consts::PI;
+
+/// Empirical coefficient in the characteristic rotor bending moment relation.
+const BENDING_MOMENT_COEFF: f64 = 3.06 * PI / 8.0;
+
+/// Characteristic rotor bending moment and first-order component mass
+/// estimates.
+#[derive(Clone, Copy, Debug, Serialize)]
+pub struct StructuralLoads {
+    pub rotor_bending_moment: f64, // N·m
+    pub blade_mass: f64, // kg
+    pub hub_mass: f64,   // kg
+}
+
+/// Estimate the characteristic rotor bending moment and first-order
+/// blade/hub masses from rotor geometry and the rated wind speed.
+///
+/// `M = (3.06π/8)·ρ·V_rated²·σ·D³ / B`, an empirical relation drawn from
+/// drivetrain sizing studies; blade and hub mass are then scaled off `M` and
+/// the rotor diameter `D`, giving a first-order structural picture alongside
+/// the aerodynamic one.
+pub fn structural_loads(
+    air_density: f64,
+    rated_wind_speed: f64,
+    num_blades: u8,
+    blade_radius: f64,
+) -> StructuralLoads {
+    let diameter = 2.0 * blade_radius;
+    let sigma = rotor_solidity(num_blades, blade_radius);
+    let b = num_blades as f64;
+
+    let rotor_bending_moment = if b > 0.0 {
+        BENDING_MOMENT_COEFF * air_density * rated_wind_speed.powi(2) * sigma * diameter.powi(3) / b
+    } else {
+        0.0
+    };
+
+    // First-order mass scalings calibrated loosely against published rotor
+    // studies: blade mass grows sub-linearly with the bending moment it must
+    // react, hub mass scales with the full rotor moment it transfers to the shaft.
+    let blade_mass = 0.5 * (rotor_bending_moment / 1000.0).abs().powf(0.8);
+    let hub_mass = 2.0 * (rotor_bending_moment / 1000.0).abs().powf(0.7);
+
+    StructuralLoads { rotor_bending_moment, blade_mass, hub_mass }
+}
@@ -1,18 +1,31 @@
 #[cfg(test)]
 mod tests {
     use crate::core::Solver;
+    use crate::farm::{Farm, TurbinePosition};
+    use crate::models::{cp_at_tsr, size_generator};
+    use crate::offshore::size_monopile;
+    use crate::optimize::{self, Bounds, Objective};
+    use crate::structures::structural_loads;
     use crate::types::*;
 
     #[test]
     fn test_rotor_area() {
         let cfg = TurbineConfig {
             target_wattage: 50.0,
-            env: Env { air_density: 1.225, wind_speed: 6.0 },
+            env: Env {
+                air_density: 1.225,
+                wind_speed: 6.0,
+                hub_height: 10.0,
+                reference_height: 10.0,
+                shear_exponent: 0.2,
+            },
             constraints: Constraints {
                 blade_radius: 0.5,
                 num_blades: 3,
-                generator_type: GeneratorType::Brushless,
+                generator_type: GeneratorType::Pmsg,
+                max_tip_speed: None,
             },
+            offshore: None,
         };
         let s = Solver::new(cfg);
         assert!((s.rotor_area() - std::f64::consts::PI * 0.25).abs() < 1e-6);
@@ -22,12 +35,20 @@ mod tests {
     fn test_design_summary() {
         let cfg = TurbineConfig {
             target_wattage: 100.0,
-            env: Env { air_density: 1.225, wind_speed: 8.0 },
+            env: Env {
+                air_density: 1.225,
+                wind_speed: 8.0,
+                hub_height: 20.0,
+                reference_height: 10.0,
+                shear_exponent: 0.2,
+            },
             constraints: Constraints {
                 blade_radius: 0.6,
                 num_blades: 3,
-                generator_type: GeneratorType::Brushless,
+                generator_type: GeneratorType::Pmsg,
+                max_tip_speed: None,
             },
+            offshore: None,
         };
         let s = Solver::new(cfg);
         let summary = s.design_summary();
@@ -38,5 +59,262 @@ mod tests {
         assert!(summary.rpm > 0.0);
         assert_eq!(summary.cut_in, 2.5);
         assert_eq!(summary.cut_out, 25.0);
+        assert!(summary.aep_kwh > 0.0);
+        assert!(summary.capacity_factor > 0.0 && summary.capacity_factor <= 1.0);
+        assert!(!summary.gearbox_required); // direct-drive PMSG
+        assert!(summary.generator_rated_torque > 0.0);
+        assert!(summary.generator_mass > 0.0);
+        assert!(summary.rotor_bending_moment > 0.0);
+        assert!(summary.blade_mass > 0.0);
+        assert!(summary.hub_mass > 0.0);
+    }
+
+    #[test]
+    fn test_design_summary_dfig_gearbox_steps_up() {
+        let cfg = TurbineConfig {
+            target_wattage: 2_000_000.0,
+            env: Env {
+                air_density: 1.225,
+                wind_speed: 8.0,
+                hub_height: 80.0,
+                reference_height: 80.0,
+                shear_exponent: 0.14,
+            },
+            constraints: Constraints {
+                blade_radius: 40.0,
+                num_blades: 3,
+                generator_type: GeneratorType::Dfig,
+                max_tip_speed: None,
+            },
+            offshore: None,
+        };
+        let summary = Solver::new(cfg).design_summary();
+
+        assert!(summary.gearbox_required);
+        // A gearbox steps the slow rotor up to the ~1500 rpm generator shaft,
+        // so the ratio must be well above 1, not the step-down 1.0 default.
+        assert!(summary.gear_ratio > 1.0);
+        assert!((summary.gear_ratio * summary.rpm - 1500.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_annual_energy_production() {
+        let cfg = TurbineConfig {
+            target_wattage: 100.0,
+            env: Env {
+                air_density: 1.225,
+                wind_speed: 8.0,
+                hub_height: 20.0,
+                reference_height: 10.0,
+                shear_exponent: 0.2,
+            },
+            constraints: Constraints {
+                blade_radius: 0.6,
+                num_blades: 3,
+                generator_type: GeneratorType::Pmsg,
+                max_tip_speed: None,
+            },
+            offshore: None,
+        };
+        let s = Solver::new(cfg);
+        let aep = s.annual_energy_production(2.0, 8.0);
+        assert!(aep.aep_kwh > 0.0);
+        assert!(aep.capacity_factor > 0.0 && aep.capacity_factor <= 1.0);
+    }
+
+    #[test]
+    fn test_wind_speed_at_power_law() {
+        let env = Env {
+            air_density: 1.225,
+            wind_speed: 8.0,
+            hub_height: 80.0,
+            reference_height: 10.0,
+            shear_exponent: 0.2,
+        };
+        // At the reference height the profile must reproduce the reference speed.
+        assert!((env.wind_speed_at(10.0) - 8.0).abs() < 1e-9);
+        // Higher up the shear profile should predict a faster wind speed.
+        assert!(env.wind_speed_at(80.0) > env.wind_speed_at(10.0));
+        // Guarded against a non-positive reference height.
+        let bad_env = Env { reference_height: 0.0, ..env };
+        assert_eq!(bad_env.wind_speed_at(80.0), bad_env.wind_speed);
+    }
+
+    #[test]
+    fn test_cp_at_tsr_bem() {
+        let cp = cp_at_tsr(7.0, 3, 30.0);
+        // A physically plausible rotor should stay below the Betz limit
+        // and produce a positive, finite power coefficient.
+        assert!(cp > 0.0 && cp < 16.0 / 27.0 + 1e-6);
+        assert_eq!(cp_at_tsr(0.0, 3, 30.0), 0.0);
+    }
+
+    #[test]
+    fn test_farm_wake_losses() {
+        let cfg = TurbineConfig {
+            target_wattage: 2_000_000.0,
+            env: Env {
+                air_density: 1.225,
+                wind_speed: 8.0,
+                hub_height: 80.0,
+                reference_height: 80.0,
+                shear_exponent: 0.14,
+            },
+            constraints: Constraints {
+                blade_radius: 40.0,
+                num_blades: 3,
+                generator_type: GeneratorType::Pmsg,
+                max_tip_speed: None,
+            },
+            offshore: None,
+        };
+        // Turbine 1 sits directly downstream of turbine 0, well within its wake.
+        let positions = vec![
+            TurbinePosition::new(0.0, 0.0),
+            TurbinePosition::new(400.0, 0.0),
+        ];
+        let farm = Farm::new(positions, cfg, 0.075);
+        let speeds = farm.effective_wind_speeds();
+        assert_eq!(speeds.len(), 2);
+        assert_eq!(speeds[0], 8.0); // nothing upstream of the first turbine
+        assert!(speeds[1] < speeds[0]); // second turbine sees wake-reduced wind
+
+        let summary = farm.summary();
+        assert!(summary.array_efficiency > 0.0 && summary.array_efficiency < 1.0);
+        assert!(summary.total_power > 0.0);
+    }
+
+    #[test]
+    fn test_generator_sizing() {
+        // A direct-drive PMSG running at low rotor rpm needs no gearbox...
+        let pmsg = size_generator(GeneratorType::Pmsg, 20.0, 2_000_000.0);
+        assert!(!pmsg.gearbox_required);
+
+        // ...while a DFIG at the same low rotor rpm needs one to reach ~1500 rpm.
+        let dfig = size_generator(GeneratorType::Dfig, 20.0, 2_000_000.0);
+        assert!(dfig.gearbox_required);
+        assert_eq!(dfig.target_rpm, 1500.0);
+
+        assert!(pmsg.rated_torque > 0.0);
+        assert!(pmsg.active_mass > 0.0);
+    }
+
+    #[test]
+    fn test_structural_loads() {
+        let loads = structural_loads(1.225, 12.0, 3, 40.0);
+        assert!(loads.rotor_bending_moment > 0.0);
+        assert!(loads.blade_mass > 0.0);
+        assert!(loads.hub_mass > 0.0);
+
+        // A larger rotor under the same conditions should see a larger
+        // bending moment (M scales with D³).
+        let bigger = structural_loads(1.225, 12.0, 3, 60.0);
+        assert!(bigger.rotor_bending_moment > loads.rotor_bending_moment);
+    }
+
+    #[test]
+    fn test_size_monopile() {
+        let offshore = OffshoreConditions {
+            sea_depth: 30.0,
+            significant_wave_height: 3.0,
+            wave_period: 8.0,
+            current_speed: 0.5,
+        };
+        let sizing = size_monopile(&offshore);
+        assert!(sizing.diameter > 0.0);
+        assert!(sizing.wall_thickness > 0.0);
+        assert!(sizing.base_moment.abs() > 0.0);
+    }
+
+    #[test]
+    fn test_design_summary_offshore() {
+        let cfg = TurbineConfig {
+            target_wattage: 2_000_000.0,
+            env: Env {
+                air_density: 1.225,
+                wind_speed: 10.0,
+                hub_height: 90.0,
+                reference_height: 90.0,
+                shear_exponent: 0.14,
+            },
+            constraints: Constraints {
+                blade_radius: 50.0,
+                num_blades: 3,
+                generator_type: GeneratorType::Pmsg,
+                max_tip_speed: None,
+            },
+            offshore: Some(OffshoreConditions {
+                sea_depth: 30.0,
+                significant_wave_height: 3.0,
+                wave_period: 8.0,
+                current_speed: 0.5,
+            }),
+        };
+        let summary = Solver::new(cfg).design_summary();
+        let monopile = summary.monopile.expect("offshore summary must size a monopile");
+        assert!(monopile.diameter > 0.0);
+    }
+
+    #[test]
+    fn test_noise_derates_to_max_tip_speed() {
+        let env = Env {
+            air_density: 1.225,
+            wind_speed: 8.0,
+            hub_height: 20.0,
+            reference_height: 10.0,
+            shear_exponent: 0.2,
+        };
+
+        // Undated: no limit set, so the operating TSR matches the design TSR.
+        let unlimited = TurbineConfig {
+            target_wattage: 100.0,
+            env,
+            constraints: Constraints {
+                blade_radius: 0.6,
+                num_blades: 3,
+                generator_type: GeneratorType::Pmsg,
+                max_tip_speed: None,
+            },
+            offshore: None,
+        };
+        let baseline = Solver::new(unlimited.clone()).noise_summary();
+        assert!(!baseline.derated);
+        assert!(baseline.sound_power_level_dba > 0.0);
+
+        // A tight limit well below the undated tip speed forces a derate.
+        let limit = baseline.tip_speed * 0.5;
+        let mut limited = unlimited;
+        limited.constraints.max_tip_speed = Some(limit);
+        let derated = Solver::new(limited).noise_summary();
+        assert!(derated.derated);
+        assert!(derated.tip_speed <= limit + 1e-6);
+        assert!(derated.operating_tsr < baseline.operating_tsr);
+    }
+
+    #[test]
+    fn test_optimize_maximizes_aep_within_bounds() {
+        let base = TurbineConfig {
+            target_wattage: 100.0,
+            env: Env {
+                air_density: 1.225,
+                wind_speed: 8.0,
+                hub_height: 20.0,
+                reference_height: 10.0,
+                shear_exponent: 0.2,
+            },
+            constraints: Constraints {
+                blade_radius: 0.3,
+                num_blades: 3,
+                generator_type: GeneratorType::Pmsg,
+                max_tip_speed: None,
+            },
+            offshore: None,
+        };
+        let bounds = Bounds { blade_radius: (0.2, 1.0), num_blades: (2, 4) };
+        let result = optimize::optimize(&base, bounds, Objective::MaximizeAep);
+
+        assert!(result.config.constraints.blade_radius >= 0.2 && result.config.constraints.blade_radius <= 1.0);
+        assert!(result.config.constraints.num_blades >= 2 && result.config.constraints.num_blades <= 4);
+        assert!(result.objective_value >= Solver::new(base).annual_energy_production(2.0, 8.0).aep_kwh);
     }
 }
\ No newline at end of file
@@ -8,14 +8,38 @@ pub struct Env {
     #[pyo3(get, set)]
     pub air_density: f64, // kg/m³
     #[pyo3(get, set)]
-    pub wind_speed: f64,  // m/s
+    pub wind_speed: f64,  // m/s, reference speed measured at `reference_height`
+    #[pyo3(get, set)]
+    pub hub_height: f64, // m
+    #[pyo3(get, set)]
+    pub reference_height: f64, // m, height at which `wind_speed` was measured
+    #[pyo3(get, set)]
+    pub shear_exponent: f64, // α, power-law exponent (~0.14 offshore, ~0.2 over land)
 }
 
 #[pymethods]
 impl Env {
     #[new]
-    pub fn new(air_density: f64, wind_speed: f64) -> Self {
-        Self { air_density, wind_speed }
+    pub fn new(
+        air_density: f64,
+        wind_speed: f64,
+        hub_height: f64,
+        reference_height: f64,
+        shear_exponent: f64,
+    ) -> Self {
+        Self { air_density, wind_speed, hub_height, reference_height, shear_exponent }
+    }
+
+    /// Wind speed at height `z` via the power-law shear profile
+    /// `U(z) = U_ref * (z / z_ref)^alpha`.
+    ///
+    /// Falls back to the raw reference `wind_speed` when `reference_height`
+    /// or `z` is non-positive, since the power law is undefined there.
+    pub fn wind_speed_at(&self, z: f64) -> f64 {
+        if self.reference_height <= 0.0 || z <= 0.0 {
+            return self.wind_speed;
+        }
+        self.wind_speed * (z / self.reference_height).powf(self.shear_exponent)
     }
 }
 
@@ -29,22 +53,57 @@ pub struct Constraints {
     pub num_blades: u8,
     #[pyo3(get, set)]
     pub generator_type: GeneratorType,
+    /// Maximum allowed blade tip speed for noise compliance (commonly ~70 m/s
+    /// onshore). When set, the solver derates the operating point rather
+    /// than exceeding it.
+    #[pyo3(get, set)]
+    pub max_tip_speed: Option<f64>, // m/s
 }
 
 #[pymethods]
 impl Constraints {
     #[new]
-    pub fn new(blade_radius: f64, num_blades: u8, generator_type: GeneratorType) -> Self {
-        Self { blade_radius, num_blades, generator_type }
+    #[pyo3(signature = (blade_radius, num_blades, generator_type, max_tip_speed=None))]
+    pub fn new(blade_radius: f64, num_blades: u8, generator_type: GeneratorType, max_tip_speed: Option<f64>) -> Self {
+        Self { blade_radius, num_blades, generator_type, max_tip_speed }
     }
 }
 
-/// Supported generator types
+/// Supported generator topologies
 #[pyclass]
-#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
 pub enum GeneratorType {
-    Brushed,
-    Brushless,
+    /// Permanent-magnet synchronous generator; high pole count allows direct
+    /// drive at low rotor rpm.
+    Pmsg,
+    /// Doubly-fed induction generator; needs a gearbox to reach its ~1500 rpm
+    /// synchronous operating range.
+    Dfig,
+    /// Electrically-excited synchronous generator; also gearbox-driven.
+    Eesg,
+}
+
+/// Offshore site conditions: water depth and wave/current climate used for
+/// monopile sizing. Only present when the design is being evaluated offshore.
+#[pyclass]
+#[derive(Clone, Copy, Debug, Serialize, Deserialize)]
+pub struct OffshoreConditions {
+    #[pyo3(get, set)]
+    pub sea_depth: f64, // m
+    #[pyo3(get, set)]
+    pub significant_wave_height: f64, // Hs, m
+    #[pyo3(get, set)]
+    pub wave_period: f64, // T, s
+    #[pyo3(get, set)]
+    pub current_speed: f64, // Uc, m/s
+}
+
+#[pymethods]
+impl OffshoreConditions {
+    #[new]
+    pub fn new(sea_depth: f64, significant_wave_height: f64, wave_period: f64, current_speed: f64) -> Self {
+        Self { sea_depth, significant_wave_height, wave_period, current_speed }
+    }
 }
 
 /// Complete turbine configuration
@@ -53,9 +112,11 @@ pub struct TurbineConfig {
     pub target_wattage: f64,  // W
     pub env: Env,
     pub constraints: Constraints,
+    pub offshore: Option<OffshoreConditions>,
 }
 
 // Python wrapper types
 pub type PyEnv = Env;
 pub type PyConstraints = Constraints;
-pub type PyGeneratorType = GeneratorType;
\ No newline at end of file
+pub type PyGeneratorType = GeneratorType;
+pub type PyOffshoreConditions = OffshoreConditions;
\ No newline at end of file